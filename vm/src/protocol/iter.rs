@@ -1,7 +1,7 @@
 use crate::IntoPyObject;
 use crate::{
-    builtins::iter::PySequenceIterator, IntoPyResult, PyObjectRef, PyResult, PyValue,
-    TryFromObject, TypeProtocol, VirtualMachine,
+    builtins::iter::PySequenceIterator, exceptions::PyBaseExceptionRef, IntoPyResult,
+    PyObjectRef, PyResult, PyValue, TryFromObject, TypeProtocol, VirtualMachine,
 };
 use std::borrow::Borrow;
 use std::ops::Deref;
@@ -51,10 +51,36 @@ where
         iternext(self.0.borrow(), vm)
     }
 
+    /// Like [`Self::next`], but returns an [`IterNextOutput`] instead of a `PyIterReturn`.
+    pub fn next_output(
+        &self,
+        vm: &VirtualMachine,
+    ) -> PyResult<IterNextOutput<PyObjectRef, PyObjectRef>> {
+        match self.next(vm)? {
+            PyIterReturn::Return(obj) => Ok(IterNextOutput::Yield(obj)),
+            PyIterReturn::StopIteration(v) => {
+                Ok(IterNextOutput::Return(v.unwrap_or_else(|| vm.ctx.none())))
+            }
+        }
+    }
+
     pub fn iter<'a, U>(&self, vm: &'a VirtualMachine) -> PyResult<PyIterIter<'a, U>> {
+        let obj = self.as_object();
+        let has_len = obj.class().mro_find_map(|x| x.slots.len.load()).is_some();
+        let length_hint = vm.length_hint(obj.clone())?;
+        let mut it = PyIterIter::new(vm, PyIter::<PyObjectRef>::new(obj.clone()), length_hint);
+        it.exact = has_len;
+        Ok(it)
+    }
+
+    /// Like [`Self::iter`], but returns a [`PyIterReturnIter`].
+    pub fn iter_with_return<'a, U>(
+        &self,
+        vm: &'a VirtualMachine,
+    ) -> PyResult<PyIterReturnIter<'a, U>> {
         let obj = self.as_object();
         let length_hint = vm.length_hint(obj.clone())?;
-        Ok(PyIterIter::new(
+        Ok(PyIterReturnIter::new(
             vm,
             PyIter::<PyObjectRef>::new(obj.clone()),
             length_hint,
@@ -128,6 +154,42 @@ impl PyObjectRef {
         // PyObject_GetIter
         PyIter::try_from_object(vm, self)
     }
+
+    /// Takes an object and returns an async iterator for it, resolving `__aiter__`
+    /// the way [`Self::get_iter`] resolves `__iter__`.
+    pub fn get_aiter(self, vm: &VirtualMachine) -> PyResult<PyAsyncIter> {
+        // PyObject_GetAIter
+        PyAsyncIter::try_from_object(vm, self)
+    }
+
+    /// Takes an object and returns an iterator over it in reverse. Prefers
+    /// `__reversed__`; if that isn't defined, falls back to the sequence protocol,
+    /// walking indices from `len() - 1` down to `0` and using
+    /// [`PyIterReturn::from_getitem_result`] to stop on `IndexError`, the way
+    /// `reversed()` does for a type that only implements `__len__`/`__getitem__`.
+    pub fn get_reverse_iter(self, vm: &VirtualMachine) -> PyResult<PyIter> {
+        // PyObject_GetReversed
+        if let Some(reversed) = vm.get_method(self.clone(), "__reversed__") {
+            let iter = vm.invoke(&reversed?, ())?;
+            return PyIter::try_from_object(vm, iter);
+        }
+
+        // No __reversed__: fall back to the sequence protocol, walking indices
+        // len-1 down to 0 and stopping on IndexError via `from_getitem_result`.
+        vm.get_method_or_type_error(self.clone(), "__getitem__", || {
+            format!("'{}' object is not reversible", self.class().name())
+        })?;
+        let len = vm.obj_len(&self)?;
+        let mut items = Vec::with_capacity(len);
+        for i in (0..len).rev() {
+            let item = vm.call_method(&self, "__getitem__", (i,));
+            match PyIterReturn::from_getitem_result(item, vm)? {
+                PyIterReturn::Return(obj) => items.push(obj),
+                PyIterReturn::StopIteration(_) => break,
+            }
+        }
+        vm.ctx.new_list(items).into_object().get_iter(vm)
+    }
 }
 
 pub enum PyIterReturn<T = PyObjectRef> {
@@ -135,6 +197,14 @@ pub enum PyIterReturn<T = PyObjectRef> {
     StopIteration(Option<PyObjectRef>),
 }
 
+/// The outcome of driving one step of iteration to completion: either another value
+/// was yielded, or iteration finished and returned a final value (the payload of a
+/// generator's `return X`, for iterators that support one).
+pub enum IterNextOutput<Y, R> {
+    Yield(Y),
+    Return(R),
+}
+
 impl PyIterReturn {
     pub fn from_pyresult(result: PyResult, vm: &VirtualMachine) -> PyResult<Self> {
         match result {
@@ -192,6 +262,7 @@ pub struct PyIterIter<'a, T> {
     vm: &'a VirtualMachine,
     obj: PyIter,
     length_hint: Option<usize>,
+    exact: bool,
     _phantom: std::marker::PhantomData<T>,
 }
 
@@ -201,9 +272,18 @@ impl<'a, T> PyIterIter<'a, T> {
             vm,
             obj,
             length_hint,
+            exact: false,
             _phantom: std::marker::PhantomData,
         }
     }
+
+    /// Whether `length_hint` is backed by `__len__` rather than only
+    /// `__length_hint__` (or nothing at all). `length_hint` itself may be `None`,
+    /// so this is the only signal callers should use to trust it as an exact count
+    /// before preallocating; `PyIterIter` does not implement `ExactSizeIterator`.
+    pub fn is_exact(&self) -> bool {
+        self.exact
+    }
 }
 
 impl<'a, T> Iterator for PyIterIter<'a, T>
@@ -227,3 +307,509 @@ where
         (self.length_hint.unwrap_or(0), self.length_hint)
     }
 }
+
+impl PyObjectRef {
+    /// Takes an object and returns a [`PyIterIter`] over it, for use through its
+    /// [`PyFallibleIterator`] impl. Unlike its plain `Iterator` impl, consumers built on
+    /// top of [`PyFallibleIterator`] stop at the first error instead of folding it into
+    /// `Item`, so a misbehaving `__next__` can't be miscounted or looped over forever.
+    /// With both traits in scope, method-call syntax picks the `Iterator` versions of
+    /// `fold`, `count`, `for_each` and `collect`; call them as
+    /// `PyFallibleIterator::for_each(&mut it, ..)` and so on.
+    pub fn try_iter<T: TryFromObject>(self, vm: &VirtualMachine) -> PyResult<PyIterIter<'_, T>> {
+        self.get_iter(vm)?.iter(vm)
+    }
+}
+
+/// A fallible iterator over the Python iteration protocol, mirroring
+/// `fallible_iterator::FallibleIterator`; the impls for [`PyIterIter`] and
+/// [`PyIterReturnIter`] set `Error` to [`PyBaseExceptionRef`]. Every adapter here stops and propagates `Err` as soon as the
+/// underlying `next` does, rather than continuing to pull from the `PyIter` the way
+/// `Iterator<Item = PyResult<T>>` combinators do.
+pub trait PyFallibleIterator: Sized {
+    type Item;
+    type Error;
+
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error>;
+
+    fn map<B, F>(self, f: F) -> PyFallibleMap<Self, F>
+    where
+        F: FnMut(Self::Item) -> Result<B, Self::Error>,
+    {
+        PyFallibleMap { it: self, f }
+    }
+
+    fn filter<F>(self, f: F) -> PyFallibleFilter<Self, F>
+    where
+        F: FnMut(&Self::Item) -> Result<bool, Self::Error>,
+    {
+        PyFallibleFilter { it: self, f }
+    }
+
+    fn take(self, n: usize) -> PyFallibleTake<Self> {
+        PyFallibleTake { it: self, n }
+    }
+
+    fn fold<B, F>(&mut self, init: B, mut f: F) -> Result<B, Self::Error>
+    where
+        F: FnMut(B, Self::Item) -> Result<B, Self::Error>,
+    {
+        let mut acc = init;
+        while let Some(item) = self.next()? {
+            acc = f(acc, item)?;
+        }
+        Ok(acc)
+    }
+
+    fn count(&mut self) -> Result<usize, Self::Error> {
+        self.fold(0, |acc, _| Ok(acc + 1))
+    }
+
+    fn for_each<F>(&mut self, mut f: F) -> Result<(), Self::Error>
+    where
+        F: FnMut(Self::Item) -> Result<(), Self::Error>,
+    {
+        self.fold((), |(), item| f(item))
+    }
+
+    fn collect<C>(&mut self) -> Result<C, Self::Error>
+    where
+        C: Default + Extend<Self::Item>,
+    {
+        let mut out = C::default();
+        while let Some(item) = self.next()? {
+            out.extend(std::iter::once(item));
+        }
+        Ok(out)
+    }
+}
+
+pub struct PyFallibleMap<I, F> {
+    it: I,
+    f: F,
+}
+
+impl<I, F, B> PyFallibleIterator for PyFallibleMap<I, F>
+where
+    I: PyFallibleIterator,
+    F: FnMut(I::Item) -> Result<B, I::Error>,
+{
+    type Item = B;
+    type Error = I::Error;
+
+    fn next(&mut self) -> Result<Option<B>, I::Error> {
+        match self.it.next()? {
+            Some(item) => Ok(Some((self.f)(item)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+pub struct PyFallibleFilter<I, F> {
+    it: I,
+    f: F,
+}
+
+impl<I, F> PyFallibleIterator for PyFallibleFilter<I, F>
+where
+    I: PyFallibleIterator,
+    F: FnMut(&I::Item) -> Result<bool, I::Error>,
+{
+    type Item = I::Item;
+    type Error = I::Error;
+
+    fn next(&mut self) -> Result<Option<I::Item>, I::Error> {
+        while let Some(item) = self.it.next()? {
+            if (self.f)(&item)? {
+                return Ok(Some(item));
+            }
+        }
+        Ok(None)
+    }
+}
+
+pub struct PyFallibleTake<I> {
+    it: I,
+    n: usize,
+}
+
+impl<I> PyFallibleIterator for PyFallibleTake<I>
+where
+    I: PyFallibleIterator,
+{
+    type Item = I::Item;
+    type Error = I::Error;
+
+    fn next(&mut self) -> Result<Option<I::Item>, I::Error> {
+        if self.n == 0 {
+            return Ok(None);
+        }
+        self.n -= 1;
+        self.it.next()
+    }
+}
+
+impl<'a, T> PyFallibleIterator for PyIterIter<'a, T>
+where
+    T: TryFromObject,
+{
+    type Item = T;
+    type Error = PyBaseExceptionRef;
+
+    fn next(&mut self) -> PyResult<Option<T>> {
+        match self.obj.next(self.vm)? {
+            PyIterReturn::Return(obj) => Ok(Some(T::try_from_object(self.vm, obj)?)),
+            PyIterReturn::StopIteration(_) => Ok(None),
+        }
+    }
+}
+
+/// Like [`PyIterIter`], but built on [`PyIter::next_output`] so it can expose the
+/// [`IterNextOutput::Return`] payload via [`Self::return_value`] once exhausted,
+/// instead of discarding it the way [`PyIterIter`] does.
+pub struct PyIterReturnIter<'a, T> {
+    vm: &'a VirtualMachine,
+    obj: PyIter,
+    length_hint: Option<usize>,
+    return_value: Option<PyObjectRef>,
+    _phantom: std::marker::PhantomData<T>,
+}
+
+impl<'a, T> PyIterReturnIter<'a, T> {
+    pub fn new(vm: &'a VirtualMachine, obj: PyIter, length_hint: Option<usize>) -> Self {
+        Self {
+            vm,
+            obj,
+            length_hint,
+            return_value: None,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// See [`IterNextOutput::Return`].
+    pub fn return_value(&self) -> Option<&PyObjectRef> {
+        self.return_value.as_ref()
+    }
+
+    /// Mirrors `Iterator::size_hint`, for callers that want a capacity estimate
+    /// before driving the fallible iteration to completion.
+    pub fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.length_hint.unwrap_or(0), self.length_hint)
+    }
+}
+
+impl<'a, T> PyFallibleIterator for PyIterReturnIter<'a, T>
+where
+    T: TryFromObject,
+{
+    type Item = T;
+    type Error = PyBaseExceptionRef;
+
+    fn next(&mut self) -> PyResult<Option<T>> {
+        match self.obj.next_output(self.vm)? {
+            IterNextOutput::Yield(obj) => Ok(Some(T::try_from_object(self.vm, obj)?)),
+            IterNextOutput::Return(v) => {
+                self.return_value = Some(v);
+                Ok(None)
+            }
+        }
+    }
+}
+
+/// Asynchronous Iterator Protocol
+// https://docs.python.org/3/reference/datamodel.html#asynchronous-iterators
+#[derive(Debug, Clone)]
+#[repr(transparent)]
+pub struct PyAsyncIter<T = PyObjectRef>(T)
+where
+    T: Borrow<PyObjectRef>;
+
+impl PyAsyncIter<PyObjectRef> {
+    pub fn into_object(self) -> PyObjectRef {
+        self.0
+    }
+    pub fn check(obj: &PyObjectRef) -> bool {
+        obj.class()
+            .mro_find_map(|x| x.slots.as_async.anext.load())
+            .is_some()
+    }
+}
+
+impl<T> PyAsyncIter<T>
+where
+    T: Borrow<PyObjectRef>,
+{
+    pub fn new(obj: T) -> Self {
+        Self(obj)
+    }
+    pub fn as_object(&self) -> &PyObjectRef {
+        self.0.borrow()
+    }
+
+    /// Calls `__anext__`/`am_anext`, returning the awaitable it produces. Driving that
+    /// awaitable to completion yields a [`PyIterReturn`], which should be mapped through
+    /// [`PyIterReturn::into_async_pyresult`] to turn an exhausted iterator into the
+    /// `StopAsyncIteration` that `async for` expects.
+    pub fn anext(&self, vm: &VirtualMachine) -> PyResult<PyObjectRef> {
+        let anext = {
+            self.0
+                .borrow()
+                .class()
+                .mro_find_map(|x| x.slots.as_async.anext.load())
+                .ok_or_else(|| {
+                    vm.new_type_error(format!(
+                        "'{}' object is not an async iterator",
+                        self.0.borrow().class().name()
+                    ))
+                })?
+        };
+        anext(self.0.borrow(), vm)
+    }
+
+    pub fn iter<'a>(&self, vm: &'a VirtualMachine) -> PyAsyncIterIter<'a> {
+        PyAsyncIterIter::new(vm, PyAsyncIter::<PyObjectRef>::new(self.as_object().clone()))
+    }
+}
+
+impl<T> Borrow<PyObjectRef> for PyAsyncIter<T>
+where
+    T: Borrow<PyObjectRef>,
+{
+    fn borrow(&self) -> &PyObjectRef {
+        self.0.borrow()
+    }
+}
+
+impl<T> Deref for PyAsyncIter<T>
+where
+    T: Borrow<PyObjectRef>,
+{
+    type Target = PyObjectRef;
+    fn deref(&self) -> &Self::Target {
+        self.0.borrow()
+    }
+}
+
+impl IntoPyObject for PyAsyncIter<PyObjectRef> {
+    fn into_pyobject(self, _vm: &VirtualMachine) -> PyObjectRef {
+        self.into_object()
+    }
+}
+
+impl TryFromObject for PyAsyncIter<PyObjectRef> {
+    fn try_from_object(vm: &VirtualMachine, aiter_target: PyObjectRef) -> PyResult<Self> {
+        let getaiter = {
+            let cls = aiter_target.class();
+            cls.mro_find_map(|x| x.slots.as_async.aiter.load())
+        };
+        if let Some(getaiter) = getaiter {
+            let aiter = getaiter(aiter_target, vm)?;
+            if PyAsyncIter::check(&aiter) {
+                Ok(Self(aiter))
+            } else {
+                Err(vm.new_type_error(format!(
+                    "aiter() returned non-async-iterator of type '{}'",
+                    aiter.class().name()
+                )))
+            }
+        } else {
+            Err(vm.new_type_error(format!(
+                "'{}' object is not async iterable",
+                aiter_target.class().name()
+            )))
+        }
+    }
+}
+
+/// Rust-side iterator over the awaitables produced by repeatedly calling `__anext__`,
+/// the asynchronous counterpart to [`PyIterIter`]. Each item must still be awaited by
+/// the caller (e.g. `async for` desugaring) to recover the yielded value or observe
+/// `StopAsyncIteration`.
+pub struct PyAsyncIterIter<'a> {
+    vm: &'a VirtualMachine,
+    obj: PyAsyncIter,
+}
+
+impl<'a> PyAsyncIterIter<'a> {
+    pub fn new(vm: &'a VirtualMachine, obj: PyAsyncIter) -> Self {
+        Self { vm, obj }
+    }
+}
+
+impl<'a> Iterator for PyAsyncIterIter<'a> {
+    type Item = PyResult<PyObjectRef>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.obj.anext(self.vm))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{compiler::Mode, Interpreter};
+
+    #[test]
+    fn test_fallible_iterator_short_circuits_on_error() {
+        Interpreter::without_stdlib(Default::default()).enter(|vm| {
+            let list = vm
+                .ctx
+                .new_list(vec![vm.ctx.new_int(1), vm.ctx.none(), vm.ctx.new_int(3)]);
+            let mut it = list.into_object().try_iter::<i32>(vm).unwrap();
+            let mut seen = 0;
+            let result = PyFallibleIterator::for_each(&mut it, |_| {
+                seen += 1;
+                Ok(())
+            });
+            assert!(result.is_err());
+            assert_eq!(seen, 1);
+        })
+    }
+
+    #[test]
+    fn test_iter_with_return_captures_generator_return_value() {
+        Interpreter::without_stdlib(Default::default()).enter(|vm| {
+            let source = "\
+def gen():
+    yield 1
+    yield 2
+    return 'done'
+result = gen()
+";
+            let code = vm.compile(source, Mode::Exec, "<test>".to_owned()).unwrap();
+            let scope = vm.new_scope_with_builtins();
+            vm.run_code_obj(code, scope.clone()).unwrap();
+            let gen_obj = scope.globals.get_item("result", vm).unwrap();
+
+            let mut it = gen_obj
+                .get_iter(vm)
+                .unwrap()
+                .iter_with_return::<i32>(vm)
+                .unwrap();
+            assert_eq!(it.next().unwrap(), Some(1));
+            assert_eq!(it.next().unwrap(), Some(2));
+            assert_eq!(it.next().unwrap(), None);
+            assert!(it.return_value().is_some());
+        })
+    }
+
+    #[test]
+    fn test_iter_with_return_propagates_errors() {
+        Interpreter::without_stdlib(Default::default()).enter(|vm| {
+            let source = "\
+def gen():
+    yield 1
+    raise ValueError('boom')
+result = gen()
+";
+            let code = vm.compile(source, Mode::Exec, "<test>".to_owned()).unwrap();
+            let scope = vm.new_scope_with_builtins();
+            vm.run_code_obj(code, scope.clone()).unwrap();
+            let gen_obj = scope.globals.get_item("result", vm).unwrap();
+
+            let mut it = gen_obj
+                .get_iter(vm)
+                .unwrap()
+                .iter_with_return::<i32>(vm)
+                .unwrap();
+            assert_eq!(it.next().unwrap(), Some(1));
+            assert!(it.next().is_err());
+        })
+    }
+
+    #[test]
+    fn test_get_aiter_resolves_aiter_and_anext() {
+        Interpreter::without_stdlib(Default::default()).enter(|vm| {
+            let source = "\
+class AIter:
+    def __aiter__(self):
+        return self
+    def __anext__(self):
+        return 42
+obj = AIter()
+";
+            let code = vm.compile(source, Mode::Exec, "<test>".to_owned()).unwrap();
+            let scope = vm.new_scope_with_builtins();
+            vm.run_code_obj(code, scope.clone()).unwrap();
+            let obj = scope.globals.get_item("obj", vm).unwrap();
+
+            let aiter = obj.get_aiter(vm).unwrap();
+            let result = aiter.anext(vm).unwrap();
+            assert_eq!(i32::try_from_object(vm, result).unwrap(), 42);
+        })
+    }
+
+    #[test]
+    fn test_get_aiter_rejects_non_async_iterable() {
+        Interpreter::without_stdlib(Default::default()).enter(|vm| {
+            let obj = vm.ctx.new_int(1);
+            assert!(obj.get_aiter(vm).is_err());
+        })
+    }
+
+    #[test]
+    fn test_get_reverse_iter_prefers_dunder_reversed() {
+        Interpreter::without_stdlib(Default::default()).enter(|vm| {
+            let source = "\
+class R:
+    def __reversed__(self):
+        return iter([3, 2, 1])
+obj = R()
+";
+            let code = vm.compile(source, Mode::Exec, "<test>".to_owned()).unwrap();
+            let scope = vm.new_scope_with_builtins();
+            vm.run_code_obj(code, scope.clone()).unwrap();
+            let obj = scope.globals.get_item("obj", vm).unwrap();
+
+            let rev = obj.get_reverse_iter(vm).unwrap();
+            let items: PyResult<Vec<i32>> = rev.iter(vm).unwrap().collect();
+            assert_eq!(items.unwrap(), vec![3, 2, 1]);
+        })
+    }
+
+    #[test]
+    fn test_get_reverse_iter_falls_back_to_sequence_protocol() {
+        Interpreter::without_stdlib(Default::default()).enter(|vm| {
+            let source = "\
+class Seq:
+    def __init__(self):
+        self.data = [10, 20, 30]
+    def __len__(self):
+        return len(self.data)
+    def __getitem__(self, i):
+        return self.data[i]
+obj = Seq()
+";
+            let code = vm.compile(source, Mode::Exec, "<test>".to_owned()).unwrap();
+            let scope = vm.new_scope_with_builtins();
+            vm.run_code_obj(code, scope.clone()).unwrap();
+            let obj = scope.globals.get_item("obj", vm).unwrap();
+
+            let rev = obj.get_reverse_iter(vm).unwrap();
+            let items: PyResult<Vec<i32>> = rev.iter(vm).unwrap().collect();
+            assert_eq!(items.unwrap(), vec![30, 20, 10]);
+        })
+    }
+
+    #[test]
+    fn test_get_reverse_iter_rejects_non_reversible() {
+        Interpreter::without_stdlib(Default::default()).enter(|vm| {
+            let obj = vm.ctx.new_int(1);
+            assert!(obj.get_reverse_iter(vm).is_err());
+        })
+    }
+
+    #[test]
+    fn test_iter_marks_exact_when_len_is_defined() {
+        Interpreter::without_stdlib(Default::default()).enter(|vm| {
+            let list = vm.ctx.new_list(vec![vm.ctx.new_int(1), vm.ctx.new_int(2)]);
+            let it = list
+                .into_object()
+                .get_iter(vm)
+                .unwrap()
+                .iter::<i32>(vm)
+                .unwrap();
+            assert!(it.is_exact());
+        })
+    }
+}